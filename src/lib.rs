@@ -15,6 +15,13 @@ mod local_pointer_chonks;
 
 mod recycling;
 
+/// The closure-/fixed-capacity-based counterparts of the top-level types.
+/// Like those, they are generic over an
+/// [`allocator_api2::alloc::Allocator`] (defaulting to `Global`), and in
+/// addition their lists and pools take a hard capacity and their
+/// `*_custom` methods allocate and free chonks through a one-off closure.
+pub mod owned;
+
 /// Allocates a value with the global allocator according to the type's layout.
 ///
 /// ## Safety
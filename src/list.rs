@@ -1,5 +1,8 @@
 use crate::*;
-use core::ptr::drop_in_place;
+use core::alloc::Layout;
+use core::marker::PhantomData;
+use core::ptr::{copy, drop_in_place};
+use allocator_api2::alloc::{AllocError, Allocator, Global};
 
 /// A chunked doubly-linked list. Efficient for the following operations:
 ///
@@ -7,76 +10,112 @@ use core::ptr::drop_in_place;
 /// * Append (at tail)
 /// * Pop    (at tail)
 ///
-/// Allows you to plug in your own allocator via closures so you can
-/// use a custom allocator on a stable rust.
-pub struct List<T, const N: usize> {
-    head: Link<ListChonk<T, N>>,
-    tail: Link<ListChonk<T, N>>,
-    len:  usize,
-    
+/// Generic over an [`Allocator`] (see the `allocator-api2` crate) so you
+/// can back the list with your own bump/pool allocator instead of the
+/// global one. Defaults to [`Global`].
+pub struct List<T, const N: usize, A: Allocator = Global> {
+    head:  Link<ListChonk<T, N>>,
+    tail:  Link<ListChonk<T, N>>,
+    len:   usize,
+    alloc: A,
 }
 
-impl<T, const N: usize> Default for List<T, N> {
+impl<T, const N: usize, A: Allocator + Default> Default for List<T, N, A> {
     #[inline(always)]
     fn default() -> Self {
-        List { head: Link::default(), tail: Link::default(), len: 0 }
+        List { head: Link::default(), tail: Link::default(), len: 0, alloc: A::default() }
     }
 }
-impl<T, const N: usize> List<T, N> {
+
+impl<T, const N: usize, A: Allocator> List<T, N, A> {
+
+    /// Creates an empty [`List`] backed by `alloc`.
+    #[inline(always)]
+    pub fn new_in(alloc: A) -> Self {
+        List { head: Link::default(), tail: Link::default(), len: 0, alloc }
+    }
 
     #[inline(always)]
     pub fn len(&self) -> usize { self.len }
 
     #[inline(always)]
     pub fn pop(&mut self) -> Option<T> {
-        unsafe { self.pop_custom(|x| dealloc(x)) }
+        let item = if let Some(tail) = self.tail.as_mut() {
+            if let Some(item) = tail.0.data.pop() {
+                Some(item)
+            } else {
+                // The tail chonk is now empty: unlink it (this also
+                // patches self.head, not just self.tail, when the tail
+                // chonk was also the head) and fall through to the new
+                // tail, if there is one.
+                let ptr = self.tail.0.unwrap();
+                self.unlink_chonk(ptr);
+                self.tail.as_mut()?.0.data.pop()
+            }
+        } else {
+            None
+        };
+        if item.is_some() { self.len -= 1; }
+        item
     }
 
+    /// Pushes `item` onto the list, allocating a new chonk if necessary.
+    ///
+    /// ## Aborts
+    ///
+    /// Calls [`handle_alloc_error`](alloc::alloc::handle_alloc_error)
+    /// (which aborts the process by default) if a new chonk is needed and
+    /// allocation fails. See [`Self::try_push`] for a fallible alternative.
     #[inline(always)]
     pub fn push(&mut self, item: T) {
-        unsafe { self.push_custom(item, || alloc::<ListChonk<T, N>>()) }
-    }
-
-    pub unsafe fn pop_custom<F>(&mut self, free: F) -> Option<T>
-    where F: FnOnce(*mut ListChonk<T, N>) {
-        if let Some(tail) = self.tail.as_mut() {
-            if let Some(item) = tail.0.data.pop() { return Some(item); }
-            let mut tail = self.tail;
-            let mut prev = tail.as_mut().unwrap().0.header.prev.take();
-            self.tail.swap(&mut prev);
-            free(tail.0.unwrap().as_ptr().cast());
-            return self.tail.as_mut()?.0.data.pop()
+        if let Err((item, _)) = self.try_push(item) {
+            let layout = Layout::new::<ListChonk<T, N>>();
+            drop(item);
+            alloc::alloc::handle_alloc_error(layout)
         }
-        None
     }
 
-    pub unsafe fn push_custom<A>(&mut self, item: T, alloc: A)
-    where A: FnOnce() -> *mut ListChonk<T, N> {
-        if let Some(tail) = self.tail.as_mut() {
-            // There's a block! Try push,fall back to fetching a new block.
-            tail.0.data.push(item)
-                .unwrap_or_else(|item| self.add_block(item, alloc))
+    /// Pushes `item` onto the list, allocating a new chonk if necessary.
+    ///
+    /// On allocation failure, hands `item` back to the caller alongside
+    /// the [`AllocError`] instead of writing through a null pointer.
+    pub fn try_push(&mut self, item: T) -> Result<(), (T, AllocError)> {
+        let result = if let Some(tail) = self.tail.as_mut() {
+            // There's a block! Try push, fall back to fetching a new block.
+            match tail.0.data.push(item) {
+                Ok(()) => Ok(()),
+                Err(item) => self.add_block(item),
+            }
         } else {
             // We will need a block.
-            self.add_first_block(item, alloc)            
-        }
+            self.add_first_block(item)
+        };
+        if result.is_ok() { self.len += 1; }
+        result
     }
 
-    unsafe fn add_first_block<A>(&mut self, item: T, alloc: A)
-    where A: FnOnce() -> *mut ListChonk<T, N> {
-        let mut chonk = ListChonk::new_with_allocator(alloc);
+    fn add_first_block(&mut self, item: T) -> Result<(), (T, AllocError)> {
+        let chonk = match ListChonk::alloc_in(&self.alloc) {
+            Some(chonk) => chonk,
+            None => return Err((item, AllocError)),
+        };
+        let mut chonk = Link(Some(chonk));
         // The chonk is promised to be empty. This mess is to avoid T: Debug.
         chonk.as_mut().unwrap().0.data.push(item).map_err(|_| ()).unwrap();
         // First chonk. Both head and tail should point to it.
         self.tail.replace(chonk);
         self.head.replace(chonk);
+        Ok(())
     }
 
-    unsafe fn add_block<A>(&mut self, item: T, alloc: A)
-    where A: FnOnce() -> *mut ListChonk<T, N> {
-        let mut chonk = ListChonk::new_with_allocator(alloc);
+    fn add_block(&mut self, item: T) -> Result<(), (T, AllocError)> {
+        let chonk = match ListChonk::alloc_in(&self.alloc) {
+            Some(chonk) => chonk,
+            None => return Err((item, AllocError)),
+        };
+        let mut chonk = Link(Some(chonk));
         // Start out by copying the tail because we need it at the end.
-        let mut old =  self.tail;
+        let mut old = self.tail;
         // Our new tail is the tail and the old tail points to the new tail.
         self.tail.replace(chonk);
         old.as_mut().unwrap().0.header.next.replace(chonk);
@@ -86,10 +125,270 @@ impl<T, const N: usize> List<T, N> {
         ch.0.data.push(item).map_err(|_| ()).unwrap();
         // The new tail should point to the old tail
         ch.0.header.prev.replace(old);
+        Ok(())
+    }
+
+    /// Borrowing, in-order (head-to-tail) iteration.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter { cur: self.head, idx: 0, remaining: self.len, _marker: PhantomData }
+    }
+
+    /// Mutably borrowing, in-order (head-to-tail) iteration.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut { cur: self.head, idx: 0, remaining: self.len, _marker: PhantomData }
+    }
+
+    /// Removes every element, in order, freeing chonks as they empty.
+    ///
+    /// If the [`Drain`] is dropped before being exhausted, the remaining
+    /// elements are dropped in place and the list is still left empty.
+    #[inline(always)]
+    pub fn drain(&mut self) -> Drain<'_, T, N, A> {
+        Drain { list: self }
+    }
+
+    /// Removes and returns every element for which `pred` returns `true`,
+    /// compacting partially-emptied chonks and unlinking (and freeing) any
+    /// chonk that becomes fully empty.
+    #[inline(always)]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, N, A, F>
+    where F: FnMut(&T) -> bool {
+        let cur = self.head;
+        ExtractIf { list: self, cur, idx: 0, pred }
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`.
+    #[inline(always)]
+    pub fn retain<F>(&mut self, mut keep: F)
+    where F: FnMut(&T) -> bool {
+        self.extract_if(move |item| !keep(item)).for_each(drop);
+    }
+
+    /// Removes and returns the first (head-most) element, freeing and
+    /// unlinking its chonk if that empties it. Chonks that are already
+    /// empty when reached (e.g. a tail emptied by `pop`) are unlinked and
+    /// freed along the way.
+    fn pop_front(&mut self) -> Option<T> {
+        loop {
+            let ptr = self.head.0?;
+            let chonk = unsafe { &mut *ptr.as_ptr() };
+            if chonk.0.data.is_empty() {
+                self.unlink_chonk(ptr);
+                continue;
+            }
+            let item = remove_from_chonk(&mut chonk.0.data, 0);
+            self.len -= 1;
+            if chonk.0.data.is_empty() {
+                self.unlink_chonk(ptr);
+            }
+            return Some(item);
+        }
+    }
+
+    /// Unlinks an (assumed already-empty) chonk from the list and frees
+    /// it, patching up `head`/`tail`/`prev`/`next` as needed. Returns the
+    /// chonk that followed it, if any.
+    fn unlink_chonk(&mut self, ptr: NonNull<ListChonk<T, N>>) -> Link<ListChonk<T, N>> {
+        let chonk = unsafe { ptr.as_ref() };
+        let mut prev = chonk.0.header.prev;
+        let mut next = chonk.0.header.next;
+        match prev.as_mut() {
+            Some(prev_chonk) => { prev_chonk.0.header.next = next; },
+            None => { self.head = next; },
+        }
+        match next.as_mut() {
+            Some(next_chonk) => { next_chonk.0.header.prev = prev; },
+            None => { self.tail = prev; },
+        }
+        unsafe { ListChonk::dealloc_in(ptr.as_ptr(), &self.alloc) };
+        next
+    }
+
+    /// Drops every remaining element and frees every chonk in one pass
+    /// over the chonks, without `pop_front`'s per-element shift. Used by
+    /// `Drop` and by an early-dropped `IntoIter`/`Drain`; `extract_if`/
+    /// `retain` keep using `pop_front`/`remove_from_chonk` since they
+    /// need genuine per-element removal.
+    fn clear(&mut self) {
+        let mut cur = self.head.take();
+        while let Some(ptr) = cur.0 {
+            let next = unsafe { ptr.as_ref() }.0.header.next;
+            unsafe { ListChonk::dealloc_in(ptr.as_ptr(), &self.alloc) };
+            cur = next;
+        }
+        self.tail = Link::default();
+        self.len = 0;
     }
 
 }
 
+impl<T, const N: usize, A: Allocator> Drop for List<T, N, A> {
+    /// Runs every remaining element's destructor and frees every chonk.
+    #[inline(always)]
+    fn drop(&mut self) { self.clear() }
+}
+
+impl<T, const N: usize, A: Allocator> IntoIterator for List<T, N, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N, A>;
+    #[inline(always)]
+    fn into_iter(self) -> IntoIter<T, N, A> { IntoIter { list: self } }
+}
+
+/// Borrowing, in-order iterator over a [`List`]. See [`List::iter`].
+pub struct Iter<'a, T, const N: usize> {
+    cur:       Link<ListChonk<T, N>>,
+    idx:       usize,
+    remaining: usize,
+    _marker:   PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let ptr = self.cur.0?;
+            let chonk = unsafe { ptr.as_ref() };
+            if self.idx < chonk.0.data.len() {
+                let item: &'a T = unsafe { &*(&chonk.0.data[self.idx] as *const T) };
+                self.idx += 1;
+                self.remaining -= 1;
+                return Some(item);
+            }
+            self.cur = chonk.0.header.next;
+            self.idx = 0;
+        }
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Iter<'a, T, N> {}
+
+/// Mutably borrowing, in-order iterator over a [`List`]. See [`List::iter_mut`].
+pub struct IterMut<'a, T, const N: usize> {
+    cur:       Link<ListChonk<T, N>>,
+    idx:       usize,
+    remaining: usize,
+    _marker:   PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            let ptr = self.cur.0?;
+            let chonk = unsafe { &mut *ptr.as_ptr() };
+            if self.idx < chonk.0.data.len() {
+                let item: &'a mut T = unsafe { &mut *(&mut chonk.0.data[self.idx] as *mut T) };
+                self.idx += 1;
+                self.remaining -= 1;
+                return Some(item);
+            }
+            self.cur = chonk.0.header.next;
+            self.idx = 0;
+        }
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for IterMut<'a, T, N> {}
+
+/// Owning, in-order iterator over a [`List`]. See [`IntoIterator`].
+///
+/// Dropping an [`IntoIter`] before exhausting it drops the remaining
+/// elements and frees their chonks.
+pub struct IntoIter<T, const N: usize, A: Allocator = Global> {
+    list: List<T, N, A>,
+}
+
+impl<T, const N: usize, A: Allocator> Iterator for IntoIter<T, N, A> {
+    type Item = T;
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> { self.list.pop_front() }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) { let len = self.list.len(); (len, Some(len)) }
+}
+
+impl<T, const N: usize, A: Allocator> ExactSizeIterator for IntoIter<T, N, A> {}
+
+// No explicit Drop impl needed: `list` is an owned field, so its own
+// Drop (a bulk chonk-at-a-time free, not a per-element pop_front loop)
+// runs automatically when an IntoIter is dropped, whether exhausted or not.
+
+/// Draining, in-order iterator over a [`List`]. See [`List::drain`].
+pub struct Drain<'a, T, const N: usize, A: Allocator = Global> {
+    list: &'a mut List<T, N, A>,
+}
+
+impl<'a, T, const N: usize, A: Allocator> Iterator for Drain<'a, T, N, A> {
+    type Item = T;
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> { self.list.pop_front() }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) { let len = self.list.len(); (len, Some(len)) }
+}
+
+impl<'a, T, const N: usize, A: Allocator> ExactSizeIterator for Drain<'a, T, N, A> {}
+
+impl<'a, T, const N: usize, A: Allocator> Drop for Drain<'a, T, N, A> {
+    // `list` is borrowed, not owned, so unlike IntoIter this can't rely
+    // on field auto-drop and must bulk-free explicitly.
+    fn drop(&mut self) { self.list.clear() }
+}
+
+/// Extracting iterator over a [`List`]. See [`List::extract_if`].
+pub struct ExtractIf<'a, T, const N: usize, A: Allocator, F>
+where F: FnMut(&T) -> bool {
+    list: &'a mut List<T, N, A>,
+    cur:  Link<ListChonk<T, N>>,
+    idx:  usize,
+    pred: F,
+}
+
+impl<'a, T, const N: usize, A: Allocator, F> Iterator for ExtractIf<'a, T, N, A, F>
+where F: FnMut(&T) -> bool {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let ptr = self.cur.0?;
+            let chonk = unsafe { &mut *ptr.as_ptr() };
+            if self.idx >= chonk.0.data.len() {
+                self.cur = if chonk.0.data.is_empty() {
+                    self.list.unlink_chonk(ptr)
+                } else {
+                    chonk.0.header.next
+                };
+                self.idx = 0;
+                continue;
+            }
+            if (self.pred)(&chonk.0.data[self.idx]) {
+                let item = remove_from_chonk(&mut chonk.0.data, self.idx);
+                self.list.len -= 1;
+                return Some(item);
+            } else {
+                self.idx += 1;
+            }
+        }
+    }
+}
+
+/// Removes and returns the element at `idx` in `data`, shifting everything
+/// after it down by one. Mirrors `Vec::remove` for a `heapless::Vec`.
+fn remove_from_chonk<T, const N: usize>(data: &mut Vec<T, N>, idx: usize) -> T {
+    let len = data.len();
+    unsafe {
+        let ptr = data.as_mut_ptr();
+        let item = ptr.add(idx).read();
+        copy(ptr.add(idx + 1), ptr.add(idx), len - idx - 1);
+        data.set_len(len - 1);
+        item
+    }
+}
+
 #[repr(transparent)] // Force chonk's layout guarantees.
 pub struct ListChonk<T, const N: usize>(pub(crate) Chonk<Links<Self>, T, N>);
 
@@ -99,27 +398,26 @@ impl<T, const N: usize> Default for ListChonk<T, N> {
 }
 
 impl<T, const N: usize> ListChonk<T, N> {
-    /// ## Safety
-    ///
-    /// The provided allocator function must return a valid and
-    /// properly aligned pointer for the type `T`.
+    /// Allocates and default-initialises a chonk with `alloc`, returning
+    /// `None` rather than writing through a null pointer on OOM.
     #[inline(always)]
-    pub unsafe fn new_with_allocator<A>(alloc: A) -> Link<ListChonk<T, N>>
-    where A: FnOnce() -> *mut ListChonk<T, N> {
-        let ptr = alloc();
-        ptr.write(Self::default());
-        Link(Some(NonNull::new_unchecked(ptr)))
+    pub(crate) fn alloc_in<A: Allocator>(alloc: &A) -> Option<NonNull<ListChonk<T, N>>> {
+        let layout = Layout::new::<ListChonk<T, N>>();
+        let ptr = alloc.allocate(layout).ok()?.cast::<ListChonk<T, N>>();
+        unsafe { ptr.as_ptr().write(Self::default()) };
+        Some(ptr)
     }
 
-
+    /// Runs `T`'s destructors in the chonk and returns its memory to `alloc`.
+    ///
     /// ## Safety
     ///
-    /// The provided pointer must be valid and properly aligned.
+    /// `chonk` must be valid, properly aligned, and must have been
+    /// allocated by `alloc`.
     #[inline(always)]
-    pub unsafe fn drop_with_allocator<F>(chonk: *mut Self, free: F)
-    where F: FnOnce(*mut ListChonk<T, N>) {
+    pub(crate) unsafe fn dealloc_in<A: Allocator>(chonk: *mut Self, alloc: &A) {
         drop_in_place(chonk);
-        free(chonk.cast());
+        alloc.deallocate(NonNull::new_unchecked(chonk).cast(), Layout::new::<Self>());
     }
 }
 
@@ -132,3 +430,132 @@ impl<T> Default for Links<T> {
     #[inline(always)]
     fn default() -> Self { Links { prev: Link::default(), next: Link::default() } }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_tracks_push_and_pop_across_chonks() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        assert_eq!(list.len(), 10);
+        for _ in 0..10 { assert!(list.pop().is_some()); }
+        assert_eq!(list.len(), 0);
+        assert!(list.pop().is_none());
+    }
+
+    #[test]
+    fn drop_of_a_populated_list_does_not_underflow() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        drop(list);
+    }
+
+    #[test]
+    fn dropping_into_iter_early_frees_remaining_chonks() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        let mut into_iter = list.into_iter();
+        assert_eq!(into_iter.next(), Some(0));
+        assert_eq!(into_iter.next(), Some(1));
+        drop(into_iter);
+    }
+
+    #[test]
+    fn dropping_drain_early_leaves_the_list_empty() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        {
+            let mut drain = list.drain();
+            assert_eq!(drain.next(), Some(0));
+            assert_eq!(drain.next(), Some(1));
+        }
+        assert_eq!(list.len(), 0);
+        assert!(list.pop().is_none());
+    }
+
+    #[test]
+    fn iter_size_hint_matches_len_after_pushes() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        assert_eq!(list.iter().len(), 10);
+        for (expected, actual) in (0..10).zip(list.iter()) {
+            assert_eq!(expected, *actual);
+        }
+    }
+
+    /// An [`Allocator`] that just forwards to [`Global`] while counting
+    /// how many times it was asked to allocate, so a test can prove the
+    /// list actually routes through the allocator it was built with
+    /// rather than always reaching for the global one.
+    struct CountingAllocator(std::rc::Rc<core::cell::Cell<usize>>);
+
+    unsafe impl Allocator for CountingAllocator {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn list_is_generic_over_a_custom_allocator() {
+        let allocations = std::rc::Rc::new(core::cell::Cell::new(0));
+        let mut list: List<i32, 4, CountingAllocator> =
+            List::new_in(CountingAllocator(allocations.clone()));
+        for i in 0..10 { list.push(i); }
+        assert_eq!(list.len(), 10);
+        assert_eq!(allocations.get(), 3); // 10 items at N=4 -> 3 chonks.
+    }
+
+    /// An [`Allocator`] that always fails, to exercise `try_push`'s OOM path.
+    struct FailingAllocator;
+
+    unsafe impl Allocator for FailingAllocator {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    #[test]
+    fn try_push_hands_the_item_back_on_allocation_failure() {
+        let mut list: List<i32, 4, FailingAllocator> = List::new_in(FailingAllocator);
+        let (item, _) = list.try_push(42).unwrap_err();
+        assert_eq!(item, 42);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn iter_mut_allows_updating_elements_in_place() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        for item in list.iter_mut() { *item *= 2; }
+        for (expected, actual) in (0..10).map(|i| i * 2).zip(list.iter()) {
+            assert_eq!(expected, *actual);
+        }
+    }
+
+    #[test]
+    fn retain_keeps_only_matching_elements_across_chonks() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        list.retain(|&x| x % 2 == 0);
+        assert_eq!(list.len(), 5);
+        for (expected, actual) in (0..10).step_by(2).zip(list.iter()) {
+            assert_eq!(expected, *actual);
+        }
+    }
+
+    #[test]
+    fn extract_if_removes_and_returns_matching_elements() {
+        let mut list = List::<i32, 4>::default();
+        for i in 0..10 { list.push(i); }
+        let removed: std::vec::Vec<i32> = list.extract_if(|&x| x % 2 == 0).collect();
+        assert_eq!(removed, vec![0, 2, 4, 6, 8]);
+        assert_eq!(list.len(), 5);
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{*, pointer_chonks::*};
 
+use core::alloc::Layout;
 use core::cell::UnsafeCell;
+use core::ptr::NonNull;
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+
 #[derive(Default)]
 pub struct LocalPointerChonks {
     chonks: UnsafeCell<PointerChonks>,
@@ -39,6 +43,36 @@ impl LocalPointerChonks {
     fn do_len(&self) -> usize {
         unsafe { self.chonks.get().as_ref() }.unwrap().len()
     }
+
+    #[inline(always)]
+    fn do_flush(&self) {
+        let chonks = unsafe { self.chonks.get().as_mut() }.unwrap();
+        while let Some(ptr) = chonks.pop::<u8>() {
+            unsafe { dealloc(ptr) }
+        }
+    }
+
+    /// Eagerly allocates up to `n` empty chonks into the pool, stopping
+    /// early (without erroring) if the global allocator fails. Returns
+    /// the number of chonks actually reserved.
+    fn do_try_reserve(&self, n: usize) -> usize {
+        let chonks = unsafe { self.chonks.get().as_mut() }.unwrap();
+        let mut reserved = 0;
+        while reserved < n {
+            let ptr = match PointerChonk::alloc_in(&Global) {
+                Some(ptr) => ptr.as_ptr(),
+                None => break,
+            };
+            match chonks.push(ptr) {
+                Ok(()) => reserved += 1,
+                Err(ptr) => {
+                    unsafe { ListChonk::dealloc_in(ptr, &Global) };
+                    break;
+                }
+            }
+        }
+        reserved
+    }
 }
 
 impl LocalPointerChonks {
@@ -72,8 +106,87 @@ impl LocalPointerChonks {
     pub fn len() -> usize {
         LOCAL_POINTER_CHONKS.with(|lpc| lpc.do_len())
     }
+
+    /// Empties the thread-local pool, returning every chonk it was
+    /// holding to the global allocator.
+    #[inline(always)]
+    pub fn flush() {
+        LOCAL_POINTER_CHONKS.with(|lpc| lpc.do_flush())
+    }
+
+    /// Replaces the thread-local pool with `pool`, flushing whatever it
+    /// was previously holding first so nothing leaks.
+    pub fn install(pool: PointerChonks) {
+        Self::flush();
+        LOCAL_POINTER_CHONKS.with(|lpc| unsafe { *lpc.chonks.get() = pool });
+    }
+
+    /// Runs `f` with read-only access to the thread-local pool.
+    pub fn borrow<R>(f: impl FnOnce(&PointerChonks) -> R) -> R {
+        LOCAL_POINTER_CHONKS.with(|lpc| f(unsafe { lpc.chonks.get().as_ref() }.unwrap()))
+    }
+
+    /// Pre-warms the thread-local pool with up to `n` freshly-allocated,
+    /// empty chonks so that many subsequent pushes can amortize their
+    /// allocation at start-up instead of paying for it on the hot path.
+    ///
+    /// Stops early and reports how many chonks it actually managed to
+    /// reserve if the global allocator fails partway through.
+    pub fn try_reserve(n: usize) -> Result<usize, AllocError> {
+        Ok(LOCAL_POINTER_CHONKS.with(|lpc| lpc.do_try_reserve(n)))
+    }
 }
 
 std::thread_local! {
     static LOCAL_POINTER_CHONKS: LocalPointerChonks = LocalPointerChonks::default();
 }
+
+/// An [`Allocator`] that recycles chonks through the thread-local
+/// [`LocalPointerChonks`] pool, only touching the global allocator for
+/// layouts that aren't chonk-shaped or when the pool is empty/full.
+#[derive(Clone, Copy, Default)]
+pub struct PoolAllocator;
+
+unsafe impl Allocator for PoolAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout != Layout::new::<PointerChonk>() {
+            return Global.allocate(layout);
+        }
+        let ptr = NonNull::new(LocalPointerChonks::pop::<u8>()).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr.cast(), layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout != Layout::new::<PointerChonk>() {
+            return Global.deallocate(ptr, layout);
+        }
+        LocalPointerChonks::push::<u8>(ptr.as_ptr().cast());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushed_chonk_is_popped_back_out_before_falling_back_to_the_allocator() {
+        LocalPointerChonks::flush();
+        let chonk: *mut ListChonk<*mut u8, CHONK_SIZE> = unsafe { alloc() };
+        unsafe { chonk.write(ListChonk::default()) };
+        LocalPointerChonks::push(chonk);
+        assert_eq!(LocalPointerChonks::len(), 1);
+        let recycled: *mut ListChonk<*mut u8, CHONK_SIZE> = LocalPointerChonks::pop();
+        assert_eq!(recycled, chonk);
+        assert_eq!(LocalPointerChonks::len(), 0);
+        unsafe { dealloc(recycled) };
+    }
+
+    #[test]
+    fn try_reserve_prewarms_the_pool_up_to_n_chonks() {
+        LocalPointerChonks::flush();
+        let reserved = LocalPointerChonks::try_reserve(3).unwrap();
+        assert_eq!(reserved, 3);
+        assert_eq!(LocalPointerChonks::len(), 3);
+        LocalPointerChonks::flush();
+    }
+}
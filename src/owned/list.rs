@@ -1,4 +1,5 @@
 use super::*;
+use core::alloc::Layout;
 use core::ptr::{NonNull, drop_in_place};
 
 /// A chunked doubly-linked list. Efficient for the following operations:
@@ -7,29 +8,41 @@ use core::ptr::{NonNull, drop_in_place};
 /// * Append (at tail)
 /// * Pop    (at tail)
 ///
-/// Allows you to plug in your own allocator via closures so you can
-/// use a custom allocator on a stable rust.
+/// Generic over an [`Allocator`] for its own [`Self::push`]/[`Self::pop`],
+/// and additionally allows you to plug in a one-off allocator via closures
+/// (the `*_custom` methods) so you can use a custom allocation strategy
+/// for a single call on stable rust.
 ///
 /// ## Note
 ///
 /// The best values of `N` will be powers of 2 as it makes the maths
 /// quicker and probably is kinder to the allocator.
-pub struct List<T, const N: usize> {
-    head: Link<ListChonk<T, N>>,
-    tail: Link<ListChonk<T, N>>,
-    len:  usize,
-    cap:  usize,
+pub struct List<T, const N: usize, A: Allocator = Global> {
+    head:  Link<ListChonk<T, N>>,
+    tail:  Link<ListChonk<T, N>>,
+    len:   usize,
+    cap:   usize,
+    alloc: A,
 }
 
-impl<T, const N: usize> Default for List<T, N> {
+impl<T, const N: usize, A: Allocator + Default> Default for List<T, N, A> {
     #[inline(always)]
     fn default() -> Self { Self::with_capacity(usize::MAX) }
 }
-impl<T, const N: usize> List<T, N> {
 
+impl<T, const N: usize, A: Allocator + Default> List<T, N, A> {
     #[inline(always)]
     pub fn with_capacity(cap: usize) -> Self {
-        List { head: Link::default(), tail: Link::default(), len: 0, cap }
+        Self::with_capacity_in(cap, A::default())
+    }
+}
+
+impl<T, const N: usize, A: Allocator> List<T, N, A> {
+
+    /// Creates an empty [`List`] with capacity `cap`, backed by `alloc`.
+    #[inline(always)]
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
+        List { head: Link::default(), tail: Link::default(), len: 0, cap, alloc }
     }
 
     #[inline(always)]
@@ -44,14 +57,80 @@ impl<T, const N: usize> List<T, N> {
     #[inline(always)]
     pub fn is_full(&self) -> bool { self.len == self.cap }
 
-    #[inline(always)]
+    /// Pops from the tail. A tail chonk left empty by a pop is freed with
+    /// this list's allocator by the next pop, which then pops from the
+    /// new tail.
     pub fn pop(&mut self) -> Option<T> {
-        unsafe { self.pop_custom(|x| dealloc(x)) }
+        let item = if let Some(tail) = self.tail.as_mut() {
+            if let Some(item) = tail.0.data.pop() {
+                Some(item)
+            } else {
+                let mut tail = self.tail;
+                let mut prev = tail.as_mut().unwrap().0.header.prev.take();
+                self.tail.swap(&mut prev);
+                // The freed chonk may also have been the head (no prev),
+                // in which case self.head would otherwise be left
+                // dangling at freed memory.
+                if self.tail.0.is_none() { self.head.take(); }
+                unsafe { ListChonk::dealloc_in(tail.0.unwrap().as_ptr(), &self.alloc) };
+                self.tail.as_mut()?.0.data.pop()
+            }
+        } else {
+            None
+        };
+        if item.is_some() { self.len -= 1; }
+        item
     }
 
-    #[inline(always)]
+    /// Pushes onto the tail, allocating a new chonk with this list's
+    /// allocator if necessary. Fails (returning `item`) if the list is
+    /// at capacity or if allocation fails.
     pub fn push(&mut self, item: T) -> Result<(), T> {
-        unsafe { self.push_custom(item, || alloc::<ListChonk<T, N>>()) }
+        if self.len == self.cap { return Err(item); }
+        if let Some(tail) = self.tail.as_mut() {
+            match tail.0.data.push(item) {
+                Ok(()) => {},
+                Err(item) => self.add_block(item)?,
+            }
+        } else {
+            self.add_first_block(item)?
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn add_first_block(&mut self, item: T) -> Result<(), T> {
+        let chonk = match ListChonk::alloc_in(&self.alloc) {
+            Some(chonk) => chonk,
+            None => return Err(item),
+        };
+        let mut chonk = Link(Some(chonk));
+        // The chonk is promised to be empty. This mess is to avoid T: Debug.
+        chonk.as_mut().unwrap().0.data.push(item).map_err(|_| ()).unwrap();
+        // First chonk. Both head and tail should point to it.
+        self.tail.replace(chonk);
+        self.head.replace(chonk);
+        Ok(())
+    }
+
+    fn add_block(&mut self, item: T) -> Result<(), T> {
+        let chonk = match ListChonk::alloc_in(&self.alloc) {
+            Some(chonk) => chonk,
+            None => return Err(item),
+        };
+        let mut chonk = Link(Some(chonk));
+        // Start out by copying the tail because we need it at the end.
+        let mut old = self.tail;
+        // Our new tail is the tail and the old tail points to the new tail.
+        self.tail.replace(chonk);
+        old.as_mut().unwrap().0.header.next.replace(chonk);
+        // Now we have to prepare the chonk.
+        let ch = chonk.as_mut().unwrap();
+        // The chonk is promised to be empty. This mess is to avoid T: Debug.
+        ch.0.data.push(item).map_err(|_| ()).unwrap();
+        // The new tail should point to the old tail
+        ch.0.header.prev.replace(old);
+        Ok(())
     }
 
     pub unsafe fn pop_custom<F>(&mut self, free: F) -> Option<T>
@@ -68,24 +147,24 @@ impl<T, const N: usize> List<T, N> {
         None
     }
 
-    pub unsafe fn push_custom<A>(&mut self, item: T, alloc: A) -> Result<(), T>
-    where A: FnOnce() -> *mut ListChonk<T, N> {
+    pub unsafe fn push_custom<Alloc>(&mut self, item: T, alloc: Alloc) -> Result<(), T>
+    where Alloc: FnOnce() -> *mut ListChonk<T, N> {
         // First check we have capacity
         if self.len == self.cap { return Err(item); }
         if let Some(tail) = self.tail.as_mut() {
             // There's a block! Try push,fall back to fetching a new block.
             tail.0.data.push(item)
-                .unwrap_or_else(|item| self.add_block(item, alloc))
+                .unwrap_or_else(|item| self.add_custom_block(item, alloc))
         } else {
             // We will need a block.
-            self.add_first_block(item, alloc)            
+            self.add_custom_first_block(item, alloc)
         }
         self.len += 1;
         Ok(())
     }
 
-    unsafe fn add_first_block<A>(&mut self, item: T, alloc: A)
-    where A: FnOnce() -> *mut ListChonk<T, N> {
+    unsafe fn add_custom_first_block<Alloc>(&mut self, item: T, alloc: Alloc)
+    where Alloc: FnOnce() -> *mut ListChonk<T, N> {
         let mut chonk = ListChonk::new_in(alloc);
         // The chonk is promised to be empty. This mess is to avoid T: Debug.
         chonk.as_mut().unwrap().0.data.push(item).map_err(|_| ()).unwrap();
@@ -94,8 +173,8 @@ impl<T, const N: usize> List<T, N> {
         self.head.replace(chonk);
     }
 
-    unsafe fn add_block<A>(&mut self, item: T, alloc: A)
-    where A: FnOnce() -> *mut ListChonk<T, N> {
+    unsafe fn add_custom_block<Alloc>(&mut self, item: T, alloc: Alloc)
+    where Alloc: FnOnce() -> *mut ListChonk<T, N> {
         let mut chonk = ListChonk::new_in(alloc);
         // Start out by copying the tail because we need it at the end.
         let mut old =  self.tail;
@@ -110,6 +189,52 @@ impl<T, const N: usize> List<T, N> {
         ch.0.header.prev.replace(old);
     }
 
+    /// Like [`Self::push_custom`], but `alloc` may fail (return `None`),
+    /// in which case `item` is handed back to the caller instead of a
+    /// new chonk being written through an invalid pointer.
+    pub unsafe fn try_push_custom<Alloc>(&mut self, item: T, alloc: Alloc) -> Result<(), T>
+    where Alloc: FnOnce() -> Option<*mut ListChonk<T, N>> {
+        // First check we have capacity
+        if self.len == self.cap { return Err(item); }
+        if let Some(tail) = self.tail.as_mut() {
+            match tail.0.data.push(item) {
+                Ok(()) => {},
+                Err(item) => self.try_add_custom_block(item, alloc)?,
+            }
+        } else {
+            self.try_add_custom_first_block(item, alloc)?
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    unsafe fn try_add_custom_first_block<Alloc>(&mut self, item: T, alloc: Alloc) -> Result<(), T>
+    where Alloc: FnOnce() -> Option<*mut ListChonk<T, N>> {
+        let mut chonk = match ListChonk::try_new_in(alloc) {
+            Some(chonk) => chonk,
+            None => return Err(item),
+        };
+        chonk.as_mut().unwrap().0.data.push(item).map_err(|_| ()).unwrap();
+        self.tail.replace(chonk);
+        self.head.replace(chonk);
+        Ok(())
+    }
+
+    unsafe fn try_add_custom_block<Alloc>(&mut self, item: T, alloc: Alloc) -> Result<(), T>
+    where Alloc: FnOnce() -> Option<*mut ListChonk<T, N>> {
+        let mut chonk = match ListChonk::try_new_in(alloc) {
+            Some(chonk) => chonk,
+            None => return Err(item),
+        };
+        let mut old = self.tail;
+        self.tail.replace(chonk);
+        old.as_mut().unwrap().0.header.next.replace(chonk);
+        let ch = chonk.as_mut().unwrap();
+        ch.0.data.push(item).map_err(|_| ()).unwrap();
+        ch.0.header.prev.replace(old);
+        Ok(())
+    }
+
 }
 
 /// An opaque fixed-size chunk used by the [`List`] to store
@@ -120,13 +245,19 @@ pub struct ListChonk<T, const N: usize>(pub(crate) Chonk<Links<Self>, T, N>);
 
 impl<T, const N: usize> Default for ListChonk<T, N> {
     #[inline(always)]
-    fn default() -> Self {
-        assert!(N > 0, "You may not create a zero-sized chonk");
-        ListChonk(Chonk::from(Links::default()))
-    }
+    fn default() -> Self { Self::new() }
 }
 
 impl<T, const N: usize> ListChonk<T, N> {
+    /// Creates an empty chonk.
+    ///
+    /// `const`, with the `N > 0` invariant enforced at compile time by
+    /// [`Chonk::new`] rather than via a runtime `assert!`, so a
+    /// [`ListChonk`] can be built directly into a `static`/`.bss` array
+    /// for `no_std` targets.
+    #[inline(always)]
+    pub const fn new() -> Self { ListChonk(Chonk::new(Links::none())) }
+
     /// ## Safety
     ///
     /// The provided allocator function must return a valid and
@@ -134,12 +265,22 @@ impl<T, const N: usize> ListChonk<T, N> {
     #[inline(always)]
     unsafe fn new_in<A>(alloc: A) -> Link<ListChonk<T, N>>
     where A: FnOnce() -> *mut ListChonk<T, N> {
-        assert!(N > 0, "You may not create a zero-sized chonk");
         let ptr = alloc();
         ptr.write(Self::default());
         Link(Some(NonNull::new_unchecked(ptr)))
     }
 
+    /// Like [`Self::new_in`], but `alloc` may fail (return `None`)
+    /// instead of handing back a pointer that gets written through
+    /// unconditionally.
+    #[inline(always)]
+    unsafe fn try_new_in<A>(alloc: A) -> Option<Link<ListChonk<T, N>>>
+    where A: FnOnce() -> Option<*mut ListChonk<T, N>> {
+        let ptr = alloc()?;
+        ptr.write(Self::default());
+        Some(Link(Some(NonNull::new_unchecked(ptr))))
+    }
+
     /// ## Safety
     ///
     /// The provided pointer must be valid, properly aligned and
@@ -147,10 +288,31 @@ impl<T, const N: usize> ListChonk<T, N> {
     #[inline(always)]
     unsafe fn drop_in<F>(chonk: *mut Self, free: F)
     where F: FnOnce(*mut ListChonk<T, N>) {
-        assert!(N > 0, "You may not drop a zero-sized chonk");
         drop_in_place(chonk);
         free(chonk.cast());
     }
+
+    /// Allocates and default-initialises a chonk with `alloc`, returning
+    /// `None` rather than writing through a null pointer on OOM.
+    #[inline(always)]
+    pub(crate) fn alloc_in<A: Allocator>(alloc: &A) -> Option<NonNull<ListChonk<T, N>>> {
+        let layout = Layout::new::<ListChonk<T, N>>();
+        let ptr = alloc.allocate(layout).ok()?.cast::<ListChonk<T, N>>();
+        unsafe { ptr.as_ptr().write(Self::default()) };
+        Some(ptr)
+    }
+
+    /// Runs `T`'s destructors in the chonk and returns its memory to `alloc`.
+    ///
+    /// ## Safety
+    ///
+    /// `chonk` must be valid, properly aligned, and must have been
+    /// allocated by `alloc`.
+    #[inline(always)]
+    pub(crate) unsafe fn dealloc_in<A: Allocator>(chonk: *mut Self, alloc: &A) {
+        drop_in_place(chonk);
+        alloc.deallocate(NonNull::new_unchecked(chonk).cast(), Layout::new::<Self>());
+    }
 }
 
 pub(crate) struct Links<T> {
@@ -160,5 +322,10 @@ pub(crate) struct Links<T> {
 
 impl<T> Default for Links<T> {
     #[inline(always)]
-    fn default() -> Self { Links { prev: Link::default(), next: Link::default() } }
+    fn default() -> Self { Self::none() }
+}
+
+impl<T> Links<T> {
+    #[inline(always)]
+    pub(crate) const fn none() -> Self { Links { prev: Link::none(), next: Link::none() } }
 }
@@ -2,13 +2,14 @@ use super::{*, pointer_chonks::*};
 use std::thread::LocalKey;
 use core::cell::UnsafeCell;
 
-pub struct LocalPointerChonks<const N: usize> {
+pub struct LocalPointerChonks<const N: usize, A: Allocator = Global> {
     chonks: UnsafeCell<PointerChonks<N>>,
+    alloc:  A,
 }
 
-impl<const N: usize> LocalPointerChonks<N> {
+impl<const N: usize, A: Allocator> LocalPointerChonks<N, A> {
     /// Pushes the provided chonk to the [`LocalPointerChonks`],
-    /// falling back to calling [`alloc::alloc::dealloc`] when full.
+    /// falling back to calling `free` when full.
     ///
     /// ## Note
     ///
@@ -26,11 +27,11 @@ impl<const N: usize> LocalPointerChonks<N> {
     /// Attempts to grab an empty chonk from the list, falling back to
     /// allocating a new one.
     #[inline(always)]
-    fn do_pop<T, A>(
+    fn do_pop<T, Alloc>(
         &self,
-        alloc: A
+        alloc: Alloc
     ) -> *mut ListChonk<* mut T, N>
-    where A: FnOnce() -> *mut ListChonk<* mut T, N> {
+    where Alloc: FnOnce() -> *mut ListChonk<* mut T, N> {
         unsafe { self.chonks.get().as_mut() }.unwrap().pop()
             .unwrap_or_else(|| alloc().cast())
     }
@@ -39,28 +40,67 @@ impl<const N: usize> LocalPointerChonks<N> {
     fn do_len(&self) -> usize {
         unsafe { self.chonks.get().as_ref() }.unwrap().len()
     }
-}
 
-impl<const N: usize> LocalPointerChonks<N> {
-    pub fn with_capacity(cap: usize) -> Self {
+    /// Like [`Self::do_pop`], but `alloc` may fail.
+    #[inline(always)]
+    fn do_try_pop<T, Alloc>(
+        &self,
+        alloc: Alloc
+    ) -> Result<*mut ListChonk<* mut T, N>, AllocError>
+    where Alloc: FnOnce() -> Result<*mut ListChonk<* mut T, N>, AllocError> {
+        match unsafe { self.chonks.get().as_mut() }.unwrap().pop() {
+            Some(ptr) => Ok(ptr),
+            None => alloc(),
+        }
+    }
+
+    /// Creates a [`LocalPointerChonks`] backed by `alloc` whose pool will
+    /// not store more than `cap` chonks.
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
         assert!(N > 0, "You may not create a LocalPointerChonks with zero-sized chonks");
         assert!(cap > 0, "You may not create a zero-sized LocalPointerChonks");
-        LocalPointerChonks { chonks: UnsafeCell::new(PointerChonks::with_capacity(cap)) }
+        LocalPointerChonks { chonks: UnsafeCell::new(PointerChonks::with_capacity(cap)), alloc }
+    }
+
+    /// See [`Self::try_reserve`].
+    fn do_try_reserve(&self, n: usize) -> usize {
+        let chonks = unsafe { self.chonks.get().as_mut() }.unwrap();
+        let mut reserved = 0;
+        while reserved < n {
+            let ptr = match PointerChonk::<N>::alloc_in(&self.alloc) {
+                Some(ptr) => ptr.as_ptr(),
+                None => break,
+            };
+            match chonks.push(ptr) {
+                Ok(()) => reserved += 1,
+                Err(ptr) => {
+                    unsafe { ListChonk::dealloc_in(ptr, &self.alloc) };
+                    break;
+                }
+            }
+        }
+        reserved
     }
+}
 
+// `LocalKey::with` requires its inner type to be `'static` (thread-local
+// storage can't hold borrowed data), so every associated function below
+// that actually reaches into a `key: &'static LocalKey<...>` needs that
+// bound on `A` too, even though the plain constructors above don't.
+impl<const N: usize, A: Allocator + 'static> LocalPointerChonks<N, A> {
     #[inline(always)]
     pub fn push<T>(
-        key: &'static LocalKey<LocalPointerChonks<N>>,
+        key: &'static LocalKey<LocalPointerChonks<N, A>>,
         chonk_ptr: *mut ListChonk<* mut T, N>
     ) {
         key.with(|lpc| {
-            lpc.do_push(chonk_ptr, |ptr| unsafe { dealloc(ptr) })
+            lpc.do_push(chonk_ptr, |ptr| unsafe { ListChonk::dealloc_in(ptr, &lpc.alloc) })
         })
     }
 
     #[inline(always)]
     pub unsafe fn push_custom<T, F>(
-        key: &'static LocalKey<LocalPointerChonks<N>>,
+        key: &'static LocalKey<LocalPointerChonks<N, A>>,
         chonk_ptr: *mut ListChonk<* mut T, N>,
         free: F
     )
@@ -69,27 +109,111 @@ impl<const N: usize> LocalPointerChonks<N> {
     }
 
     #[inline(always)]
-    pub fn pop<T>(key: &'static LocalKey<LocalPointerChonks<N>>) -> *mut ListChonk<* mut T, N> {
-        key.with(|lpc| lpc.do_pop(|| unsafe { alloc() }))
+    pub fn pop<T>(key: &'static LocalKey<LocalPointerChonks<N, A>>) -> *mut ListChonk<* mut T, N> {
+        key.with(|lpc| lpc.do_pop(|| {
+            ListChonk::alloc_in(&lpc.alloc).map(|p| p.as_ptr()).unwrap_or_else(|| {
+                alloc::alloc::handle_alloc_error(core::alloc::Layout::new::<ListChonk<* mut T, N>>())
+            })
+        }))
     }
 
     #[inline(always)]
-    pub unsafe fn pop_custom<T, A>(
-        key: &'static LocalKey<LocalPointerChonks<N>>,
-        alloc: A
+    pub unsafe fn pop_custom<T, Alloc>(
+        key: &'static LocalKey<LocalPointerChonks<N, A>>,
+        alloc: Alloc
     ) -> *mut ListChonk<* mut T, N>
-    where A: FnOnce() -> *mut ListChonk<* mut T, N> {
+    where Alloc: FnOnce() -> *mut ListChonk<* mut T, N> {
         key.with(|lpc| { lpc.do_pop(alloc) })
     }
 
     #[inline(always)]
-    pub fn len(key: &'static LocalKey<LocalPointerChonks<N>>) -> usize {
+    pub fn len(key: &'static LocalKey<LocalPointerChonks<N, A>>) -> usize {
         key.with(|lpc| lpc.do_len())
     }
+
+    /// Like [`Self::pop`], but reports an [`AllocError`] instead of
+    /// aborting when the pool is empty and the allocator fails.
+    #[inline(always)]
+    pub fn try_pop<T>(
+        key: &'static LocalKey<LocalPointerChonks<N, A>>
+    ) -> Result<*mut ListChonk<* mut T, N>, AllocError> {
+        key.with(|lpc| lpc.do_try_pop(|| ListChonk::alloc_in(&lpc.alloc).map(|p| p.as_ptr()).ok_or(AllocError)))
+    }
+
+    /// Like [`Self::pop_custom`], but `alloc` may fail.
+    #[inline(always)]
+    pub unsafe fn try_pop_custom<T, Alloc>(
+        key: &'static LocalKey<LocalPointerChonks<N, A>>,
+        alloc: Alloc
+    ) -> Result<*mut ListChonk<* mut T, N>, AllocError>
+    where Alloc: FnOnce() -> Result<*mut ListChonk<* mut T, N>, AllocError> {
+        key.with(|lpc| { lpc.do_try_pop(alloc) })
+    }
+
+    /// Eagerly allocates up to `n` empty chonks into the pool behind
+    /// `key`, stopping early (without erroring) if `A` fails to
+    /// allocate. Returns the number of chonks actually reserved, so a
+    /// caller can amortize that many future pushes against the
+    /// allocator up-front.
+    pub fn try_reserve(
+        key: &'static LocalKey<LocalPointerChonks<N, A>>,
+        n: usize
+    ) -> Result<usize, AllocError> {
+        Ok(key.with(|lpc| lpc.do_try_reserve(n)))
+    }
 }
 
-impl<const N: usize> Default for LocalPointerChonks<N> {
+impl<const N: usize, A: Allocator + Default> LocalPointerChonks<N, A> {
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_in(cap, A::default())
+    }
+}
+
+impl<const N: usize, A: Allocator + Default> Default for LocalPointerChonks<N, A> {
     #[inline(always)]
     fn default() -> Self { Self::with_capacity(8 * (N + 1)) }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::alloc::Layout;
+    use core::ptr::NonNull;
+
+    std::thread_local! {
+        static POOL: LocalPointerChonks<4, Global> = LocalPointerChonks::with_capacity_in(4, Global);
+    }
+
+    #[test]
+    fn push_then_pop_reuses_the_pooled_chonk() {
+        let chonk: *mut ListChonk<*mut u8, 4> = LocalPointerChonks::pop(&POOL);
+        LocalPointerChonks::push(&POOL, chonk);
+        assert_eq!(LocalPointerChonks::len(&POOL), 1);
+        let reused: *mut ListChonk<*mut u8, 4> = LocalPointerChonks::pop(&POOL);
+        assert_eq!(reused, chonk);
+        assert_eq!(LocalPointerChonks::len(&POOL), 0);
+        unsafe { ListChonk::dealloc_in(reused, &Global) };
+    }
+
+    /// An [`Allocator`] that always fails, to exercise `try_pop`'s OOM path.
+    struct FailingAllocator;
+
+    unsafe impl Allocator for FailingAllocator {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    std::thread_local! {
+        static EMPTY_POOL: LocalPointerChonks<4, FailingAllocator> =
+            LocalPointerChonks::with_capacity_in(4, FailingAllocator);
+    }
+
+    #[test]
+    fn try_pop_surfaces_allocation_failure_instead_of_aborting() {
+        let result: Result<*mut ListChonk<*mut u8, 4>, AllocError> =
+            LocalPointerChonks::try_pop(&EMPTY_POOL);
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use heapless::Vec;
+use allocator_api2::alloc::{Allocator, AllocError, Global};
 
 mod link;
 pub(crate) use link::*;
@@ -30,6 +31,17 @@ pub struct Chonk<H, T, const N: usize> {
 }
 
 impl<H, T, const N: usize> Chonk<H, T, N> {
+    /// Creates a chonk from `header`, with an empty `data`.
+    ///
+    /// `const`, with the `N > 0` invariant enforced at compile time
+    /// rather than via a runtime `assert!`, so a [`Chonk`] can be built
+    /// directly into a `static`/`.bss` array for `no_std` targets.
+    #[inline(always)]
+    pub const fn new(header: H) -> Self {
+        const { assert!(N > 0, "You may not create a zero-sized chonk") };
+        Chonk { header, data: Vec::new() }
+    }
+
     #[inline(always)]
     pub fn push(&mut self, item: T) -> Result<(), T> { self.data.push(item) }
     #[inline(always)]
@@ -44,28 +56,5 @@ impl<H, T, const N: usize> Chonk<H, T, N> {
 
 impl<H, T, const N: usize> From<H> for Chonk<H, T, N> {
     #[inline(always)]
-    fn from(header: H) -> Self {
-        assert!(N > 0, "You may not create a zero-sized chonk");
-        Chonk { header, data: Vec::new() }
-    }
-}
-
-/// Allocates a value with the global allocator according to the type's layout.
-///
-/// ## Safety
-///
-/// Basically everything about calling the global allocator as usual
-/// applies, except it will allocate it with `T`s layout.
-unsafe fn alloc<T>() -> *mut T {
-    let layout = alloc::alloc::Layout::new::<T>();
-    alloc::alloc::alloc(layout).cast()
-}
-
-/// ## Safety
-///
-/// Basically everything about calling the global allocator as usual
-/// applies, except it will deallocate it with `T`s layout.
-unsafe fn dealloc<T>(ptr: *mut T) {
-    let layout = alloc::alloc::Layout::new::<T>();
-    alloc::alloc::dealloc(ptr.cast(), layout);
+    fn from(header: H) -> Self { Self::new(header) }
 }
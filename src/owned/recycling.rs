@@ -1,12 +1,12 @@
 use super::{*, local_pointer_chonks::*};
 use std::thread::LocalKey;
 
-pub struct RecyclingList<T, const N: usize> {
-    key:  &'static LocalKey<LocalPointerChonks<N>>,
+pub struct RecyclingList<T, const N: usize, A: Allocator + 'static = Global> {
+    key:  &'static LocalKey<LocalPointerChonks<N, A>>,
     list: List<*mut T, N>,
 }
 
-impl<T, const N: usize> RecyclingList<T, N> {
+impl<T, const N: usize, A: Allocator + 'static> RecyclingList<T, N, A> {
     #[inline(always)]
     pub fn len(&self) -> usize { self.list.len() }
 
@@ -27,8 +27,8 @@ impl<T, const N: usize> RecyclingList<T, N> {
     }
 
    #[inline(always)]
-    pub unsafe fn push_custom<A>(&mut self, item: Box<T>, alloc: A) -> Result<(), Box<T>>
-    where A: FnOnce() -> *mut ListChonk<* mut T, N> {
+    pub unsafe fn push_custom<F>(&mut self, item: Box<T>, alloc: F) -> Result<(), Box<T>>
+    where F: FnOnce() -> *mut ListChonk<* mut T, N> {
         let item = Box::leak(item);
         let key = self.key;
         self.list.push_custom(item, || {
@@ -36,6 +36,29 @@ impl<T, const N: usize> RecyclingList<T, N> {
         }).map_err(|x| Box::from_raw(x))
     }
 
+    /// Like [`Self::push`], but reports the item back instead of
+    /// aborting when no chonk can be obtained from the pool or the
+    /// allocator backing the thread-local pool.
+    #[inline(always)]
+    pub fn try_push(&mut self, item: Box<T>) -> Result<(), Box<T>> {
+        let item = Box::leak(item);
+        let key = self.key;
+        unsafe {
+            self.list.try_push_custom(item, || LocalPointerChonks::try_pop(key).ok())
+                .map_err(|x| Box::from_raw(x))
+        }
+    }
+
+    /// Like [`Self::push_custom`], but `alloc` may fail.
+    #[inline(always)]
+    pub unsafe fn try_push_custom<F>(&mut self, item: Box<T>, alloc: F) -> Result<(), Box<T>>
+    where F: FnOnce() -> Result<*mut ListChonk<* mut T, N>, AllocError> {
+        let item = Box::leak(item);
+        let key = self.key;
+        self.list.try_push_custom(item, || LocalPointerChonks::try_pop_custom(key, alloc).ok())
+            .map_err(|x| Box::from_raw(x))
+    }
+
     #[inline(always)]
     pub fn pop(&mut self) -> Box<T> {
         let key = self.key;
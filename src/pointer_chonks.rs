@@ -1,10 +1,13 @@
 use crate::*;
+use core::alloc::Layout;
+use core::ptr::drop_in_place;
+use allocator_api2::alloc::{Allocator, Global};
 
 pub const CHONK_SIZE: usize = 32;
 
 pub type PointerChonk = ListChonk<*mut u8, CHONK_SIZE>;
 
-pub struct PointerChonks {
+pub struct PointerChonks<A: Allocator = Global> {
     /// Start pointer
     head: Link<PointerChonk>,
     /// End pointer
@@ -13,33 +16,46 @@ pub struct PointerChonks {
     length: usize,
     /// Maximum size we are allowed to grow to.
     capacity: usize,
+    /// The allocator backing chonks handed out by [`Self::pop`].
+    alloc: A,
 }
 
-impl Default for PointerChonks {
+impl<A: Allocator + Default> Default for PointerChonks<A> {
     #[inline(always)]
     fn default() -> Self { Self::with_capacity(CHONK_SIZE * (CHONK_SIZE + 1)) }
 }
-impl PointerChonks {
-
-    #[inline(always)]
-    pub fn len(&self) -> usize { self.length }
-
-    #[inline(always)]
-    pub fn capacity(&self) -> usize { self.capacity }
 
+impl<A: Allocator + Default> PointerChonks<A> {
     /// Creates a [`PointerChonks`] that will not store more than
     /// `capacity` chonks. This does not change how allocation happens
     /// at all, it merely imposes a limit on maximum length.
     #[inline(always)]
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, A::default())
+    }
+}
+
+impl<A: Allocator> PointerChonks<A> {
+
+    /// Creates a [`PointerChonks`] backed by `alloc` that will not store
+    /// more than `capacity` chonks.
+    #[inline(always)]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         PointerChonks {
             head: Link::default(),
             tail: Link::default(),
             length: 0,
             capacity,
+            alloc,
         }
     }
 
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.length }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> usize { self.capacity }
+
 
     pub fn pop<T>(&mut self) -> Option<*mut ListChonk<* mut T, CHONK_SIZE>> {
         if let Some(tail) = self.tail.as_mut() {
@@ -48,7 +64,7 @@ impl PointerChonks {
             self.length -= 1;
             // Try and pop an item off the tail
             if let Some(item) = tail.0.data.pop() {
-                // Success. 
+                // Success.
                 return Some(Self::init(item.cast()))
             }
             // No? Give them the block itself.
@@ -108,7 +124,7 @@ impl PointerChonks {
                 tail.0.header.next.replace(chonk);                        // The existing tail should point to us.
                 chonk.as_mut().unwrap().0.header.prev.replace(self.tail); // And we should point to the existing tail
                 self.tail.replace(chonk);                                 // We are the new tail.
-            } 
+            }
         } else {
             // It can be the first block.
             let chonk = Link(Some(unsafe { NonNull::new_unchecked(Self::init(chonk_ptr)) }));
@@ -119,3 +135,27 @@ impl PointerChonks {
         Ok(())
     }
 }
+
+impl<A: Allocator> Drop for PointerChonks<A> {
+    fn drop(&mut self) {
+        let mut cur = self.head;
+        while let Some(ptr) = cur.0 {
+            let chonk = unsafe { ptr.as_ref() };
+            // Every pointer stored in a structural chonk's `data` is a
+            // pooled, already-dropped chonk of the same layout that was
+            // never re-initialised (that only happens on `pop`), so only
+            // its memory needs reclaiming, not its (non-existent) contents.
+            for &pooled in chonk.0.data.iter() {
+                unsafe {
+                    self.alloc.deallocate(NonNull::new_unchecked(pooled), Layout::new::<PointerChonk>());
+                }
+            }
+            let next = chonk.0.header.next;
+            unsafe {
+                drop_in_place(ptr.as_ptr());
+                self.alloc.deallocate(ptr.cast(), Layout::new::<PointerChonk>());
+            }
+            cur = next;
+        }
+    }
+}
@@ -1,7 +1,21 @@
-use crate::{*, local_pointer_chonks::*, pointer_chonks::*};
+use crate::{*, pointer_chonks::*};
 
+#[cfg(feature = "recycling")]
+type RecyclingAlloc = crate::local_pointer_chonks::PoolAllocator;
+#[cfg(not(feature = "recycling"))]
+type RecyclingAlloc = allocator_api2::alloc::Global;
+
+/// A [`List`] of boxed `T`s. Under the `recycling` feature, emptied and
+/// newly-needed chonks are recycled through the thread-local
+/// [`LocalPointerChonks`](crate::local_pointer_chonks::LocalPointerChonks)
+/// pool instead of always going to the global allocator.
 pub struct RecyclingList<T> {
-    list: List<*mut T, CHONK_SIZE>,
+    list: List<*mut T, CHONK_SIZE, RecyclingAlloc>,
+}
+
+impl<T> Default for RecyclingList<T> {
+    #[inline(always)]
+    fn default() -> Self { RecyclingList { list: List::default() } }
 }
 
 impl<T> RecyclingList<T> {
@@ -11,15 +25,19 @@ impl<T> RecyclingList<T> {
     #[inline(always)]
     pub fn push(&mut self, item: Box<T>) {
         let item = Box::leak(item);
-        unsafe {
-            self.list.push_custom(item, || LocalPointerChonks::pop())
-        }
+        self.list.push(item)
+    }
+
+    /// Like [`Self::push`], but hands `item` back instead of aborting
+    /// when a new chonk is needed and allocation fails.
+    #[inline(always)]
+    pub fn try_push(&mut self, item: Box<T>) -> Result<(), Box<T>> {
+        let item = Box::leak(item);
+        self.list.try_push(item).map_err(|(item, _)| unsafe { Box::from_raw(item) })
     }
 
     #[inline(always)]
     pub fn pop(&mut self) -> Box<T> {
-        unsafe {
-            Box::from_raw(self.list.pop_custom(|i| LocalPointerChonks::push(i)).unwrap())
-        }
+        unsafe { Box::from_raw(self.list.pop().unwrap()) }
     }
 }